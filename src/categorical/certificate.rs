@@ -0,0 +1,142 @@
+//! Exact positive-definiteness certificates via square-root-free LDLᵀ
+//!
+//! A categorical operation only genuinely extracts a finite-type root
+//! system if the Gram matrix of its simple roots is positive definite —
+//! an affine or indefinite Cartan matrix would mean the "extraction" is a
+//! degenerate partition rather than a real exceptional group. This module
+//! certifies positive-definiteness with exact rational arithmetic, using
+//! the square-root-free LDLᵀ (Cholesky) factorization: process rows
+//! `i = 1…n`, computing the pivot
+//!
+//! ```text
+//! d_i = M_ii − Σ_{k<i} L_ik² · d_k
+//! ```
+//!
+//! and the off-diagonal entries
+//!
+//! ```text
+//! L_ji = (M_ji − Σ_{k<i} L_jk · L_ik · d_k) / d_i
+//! ```
+//!
+//! A matrix is positive definite iff every pivot `d_i > 0`. The `(L, D)`
+//! pair is kept as an explicit certificate so a skeptic can reconstruct
+//! `L D Lᵀ` independently and check it against the original matrix, rather
+//! than trusting the factorization routine.
+
+use crate::categorical::rational::Rational;
+
+/// An LDLᵀ certificate for a symmetric matrix, computed in exact rational
+/// arithmetic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdltCertificate {
+    /// The unit lower-triangular factor `L` (`L_ii = 1`).
+    pub l: Vec<Vec<Rational>>,
+    /// The diagonal pivots `D`.
+    pub d: Vec<Rational>,
+}
+
+impl LdltCertificate {
+    /// Factorize a symmetric matrix as `M = L D Lᵀ`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not square.
+    #[must_use]
+    pub fn factorize(matrix: &[Vec<Rational>]) -> Self {
+        let n = matrix.len();
+        assert!(matrix.iter().all(|row| row.len() == n), "matrix must be square");
+
+        let mut l = vec![vec![Rational::ZERO; n]; n];
+        let mut d = vec![Rational::ZERO; n];
+
+        for i in 0..n {
+            let mut pivot = matrix[i][i];
+            for k in 0..i {
+                pivot = pivot - l[i][k] * l[i][k] * d[k];
+            }
+            d[i] = pivot;
+            l[i][i] = Rational::ONE;
+
+            if pivot.is_zero() {
+                continue; // degenerate pivot; entries below stay zero
+            }
+            for j in (i + 1)..n {
+                let mut off_diagonal = matrix[j][i];
+                for k in 0..i {
+                    off_diagonal = off_diagonal - l[j][k] * l[i][k] * d[k];
+                }
+                l[j][i] = off_diagonal / pivot;
+            }
+        }
+
+        Self { l, d }
+    }
+
+    /// Whether every pivot is strictly positive — the matrix is positive
+    /// definite iff this holds.
+    #[must_use]
+    pub fn is_positive_definite(&self) -> bool {
+        self.d.iter().all(Rational::is_positive)
+    }
+
+    /// Independently re-verify the certificate by reconstructing `L D Lᵀ`
+    /// and checking it equals `matrix`, without trusting
+    /// [`factorize`](Self::factorize)'s own bookkeeping.
+    #[must_use]
+    pub fn reconstructs(&self, matrix: &[Vec<Rational>]) -> bool {
+        for (row_l, row_matrix) in self.l.iter().zip(matrix) {
+            for (col_l, &expected) in self.l.iter().zip(row_matrix) {
+                let entry = row_l
+                    .iter()
+                    .zip(&self.d)
+                    .zip(col_l)
+                    .fold(Rational::ZERO, |acc, ((&l_ik, &d_k), &l_jk)| acc + l_ik * d_k * l_jk);
+                if entry != expected {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categorical::root_system::{construct_f4, construct_g2};
+
+    fn rat_matrix(rows: &[&[i64]]) -> Vec<Vec<Rational>> {
+        rows.iter().map(|row| row.iter().map(|&n| Rational::from_int(n)).collect()).collect()
+    }
+
+    #[test]
+    fn g2_gram_matrix_is_positive_definite() {
+        let certificate = LdltCertificate::factorize(&construct_g2().gram_matrix());
+        assert!(certificate.is_positive_definite());
+        assert!(certificate.reconstructs(&construct_g2().gram_matrix()));
+    }
+
+    #[test]
+    fn f4_gram_matrix_is_positive_definite() {
+        let certificate = LdltCertificate::factorize(&construct_f4().gram_matrix());
+        assert!(certificate.is_positive_definite());
+        assert!(certificate.reconstructs(&construct_f4().gram_matrix()));
+    }
+
+    #[test]
+    fn indefinite_matrix_is_rejected() {
+        // det = 1*1 - 2*2 = -3 < 0, so this is indefinite, not positive definite.
+        let matrix = rat_matrix(&[&[1, 2], &[2, 1]]);
+        let certificate = LdltCertificate::factorize(&matrix);
+        assert!(!certificate.is_positive_definite());
+    }
+
+    #[test]
+    fn affine_cartan_like_matrix_is_rejected() {
+        // The affine Ã1 Cartan matrix [[2,-2],[-2,2]] is positive *semi*definite,
+        // not positive definite: its second pivot is zero.
+        let matrix = rat_matrix(&[&[2, -2], &[-2, 2]]);
+        let certificate = LdltCertificate::factorize(&matrix);
+        assert!(!certificate.is_positive_definite());
+    }
+}
@@ -0,0 +1,282 @@
+//! Interchange-format export and round-trip import of root-system data
+//!
+//! A [`RootSystem`] constructed in this crate is only useful as an
+//! independent check if it can be re-verified in established
+//! computer-algebra tooling. This module serializes the roots, simple
+//! roots, and Cartan matrix to a plain JSON interchange format, and to a
+//! GAP/Sage-style record literal, so that a user can paste the output into
+//! GAP, Sage, or any other root-system library and confirm it sees the
+//! same Cartan type. [`parse_json`] round-trips the JSON form back into a
+//! [`RootSystem`], so externally-computed root data can be loaded back and
+//! compared — via [`equivalent_up_to_weyl_symmetry`] — against the
+//! Atlas-derived construction.
+
+use crate::categorical::rational::Rational;
+use crate::categorical::root_system::RootSystem;
+
+fn rational_to_json(r: Rational) -> String {
+    format!("[{},{}]", r.numerator(), r.denominator())
+}
+
+fn matrix_to_json(matrix: &[Vec<Rational>]) -> String {
+    let rows: Vec<String> = matrix
+        .iter()
+        .map(|row| format!("[{}]", row.iter().copied().map(rational_to_json).collect::<Vec<_>>().join(",")))
+        .collect();
+    format!("[{}]", rows.join(","))
+}
+
+/// Serialize a [`RootSystem`] to a plain JSON object with `roots`,
+/// `simple_roots`, and `cartan_matrix` fields. Each rational is encoded as
+/// a `[numerator, denominator]` pair so no precision is lost.
+#[must_use]
+pub fn to_json(system: &RootSystem) -> String {
+    format!(
+        "{{\"roots\":{},\"simple_roots\":{},\"cartan_matrix\":{}}}",
+        matrix_to_json(&system.roots),
+        matrix_to_json(&system.simple_roots),
+        matrix_to_json(&system.cartan_matrix),
+    )
+}
+
+fn rational_to_gap(r: Rational) -> String {
+    if r.denominator() == 1 {
+        r.numerator().to_string()
+    } else {
+        format!("{}/{}", r.numerator(), r.denominator())
+    }
+}
+
+fn matrix_to_gap(matrix: &[Vec<Rational>]) -> String {
+    let rows: Vec<String> = matrix
+        .iter()
+        .map(|row| format!("[ {} ]", row.iter().copied().map(rational_to_gap).collect::<Vec<_>>().join(", ")))
+        .collect();
+    format!("[ {} ]", rows.join(", "))
+}
+
+/// Serialize a [`RootSystem`] to a GAP/Sage-style record literal:
+/// `rec( roots := [...], simpleRoots := [...], cartanMatrix := [...] )`.
+#[must_use]
+pub fn to_gap_literal(system: &RootSystem) -> String {
+    format!(
+        "rec( roots := {}, simpleRoots := {}, cartanMatrix := {} )",
+        matrix_to_gap(&system.roots),
+        matrix_to_gap(&system.simple_roots),
+        matrix_to_gap(&system.cartan_matrix),
+    )
+}
+
+/// A minimal parsed JSON value: only the shapes [`to_json`] ever produces
+/// (nested arrays of integers) are needed here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum JsonValue {
+    Array(Vec<JsonValue>),
+    Number(i64),
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn parse_json_value(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<JsonValue, String> {
+    skip_whitespace(chars);
+    match chars.peek() {
+        Some('[') => {
+            chars.next();
+            let mut items = Vec::new();
+            skip_whitespace(chars);
+            if chars.peek() == Some(&']') {
+                chars.next();
+                return Ok(JsonValue::Array(items));
+            }
+            loop {
+                items.push(parse_json_value(chars)?);
+                skip_whitespace(chars);
+                match chars.next() {
+                    Some(',') => continue,
+                    Some(']') => break,
+                    other => return Err(format!("expected ',' or ']', found {other:?}")),
+                }
+            }
+            Ok(JsonValue::Array(items))
+        },
+        Some(&c) if c.is_ascii_digit() || c == '-' => {
+            let mut buf = String::new();
+            while matches!(chars.peek(), Some(&c) if c.is_ascii_digit() || c == '-') {
+                buf.push(chars.next().expect("peeked"));
+            }
+            buf.parse::<i64>().map(JsonValue::Number).map_err(|e| e.to_string())
+        },
+        other => Err(format!("unexpected character: {other:?}")),
+    }
+}
+
+/// Find the `"key": [...]` array belonging to `key` in `json`, returning
+/// its substring including the enclosing brackets.
+fn extract_array<'a>(json: &'a str, key: &str) -> Result<&'a str, String> {
+    let marker = format!("\"{key}\":");
+    let after_key = json.find(&marker).map(|i| i + marker.len()).ok_or_else(|| format!("missing key \"{key}\""))?;
+    let bracket_start =
+        json[after_key..].find('[').map(|i| i + after_key).ok_or_else(|| format!("expected '[' after \"{key}\""))?;
+
+    let mut depth = 0i32;
+    for (i, c) in json[bracket_start..].char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(&json[bracket_start..=bracket_start + i]);
+                }
+            },
+            _ => {},
+        }
+    }
+    Err(format!("unterminated array for key \"{key}\""))
+}
+
+fn json_to_matrix(value: &JsonValue) -> Result<Vec<Vec<Rational>>, String> {
+    let JsonValue::Array(rows) = value else {
+        return Err("expected an array of rows".to_string());
+    };
+    rows.iter()
+        .map(|row| {
+            let JsonValue::Array(pairs) = row else {
+                return Err("expected an array of [numerator, denominator] pairs".to_string());
+            };
+            pairs
+                .iter()
+                .map(|pair| {
+                    let JsonValue::Array(nd) = pair else {
+                        return Err("expected a [numerator, denominator] pair".to_string());
+                    };
+                    match nd.as_slice() {
+                        [JsonValue::Number(n), JsonValue::Number(d)] => Rational::try_new(*n, *d)
+                            .ok_or_else(|| format!("malformed [numerator, denominator] pair: [{n}, {d}]")),
+                        _ => Err("expected exactly [numerator, denominator]".to_string()),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn parse_matrix_field(json: &str, key: &str) -> Result<Vec<Vec<Rational>>, String> {
+    let array_source = extract_array(json, key)?;
+    let value = parse_json_value(&mut array_source.chars().peekable())?;
+    json_to_matrix(&value)
+}
+
+/// Parse a [`RootSystem`] back out of the JSON [`to_json`] produces.
+///
+/// # Errors
+///
+/// Returns a description of the first parse failure: a missing field, an
+/// unbalanced array, or a malformed `[numerator, denominator]` pair.
+pub fn parse_json(json: &str) -> Result<RootSystem, String> {
+    Ok(RootSystem {
+        roots: parse_matrix_field(json, "roots")?,
+        simple_roots: parse_matrix_field(json, "simple_roots")?,
+        cartan_matrix: parse_matrix_field(json, "cartan_matrix")?,
+    })
+}
+
+/// Whether two root systems are isomorphic up to relabeling their simple
+/// roots: same rank and cardinality, and Cartan matrices related by some
+/// permutation of simple-root indices. This is the standard notion of
+/// root-system equivalence, and is exactly what "the same Cartan type" in
+/// an external CAS means, independent of the basis the simple roots
+/// happened to be expressed in.
+#[must_use]
+pub fn equivalent_up_to_weyl_symmetry(a: &RootSystem, b: &RootSystem) -> bool {
+    a.roots.len() == b.roots.len() && cartan_matrices_equivalent(&a.cartan_matrix, &b.cartan_matrix)
+}
+
+fn cartan_matrices_equivalent(a: &[Vec<Rational>], b: &[Vec<Rational>]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let n = a.len();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    loop {
+        if (0..n).all(|i| (0..n).all(|j| a[i][j] == b[permutation[i]][permutation[j]])) {
+            return true;
+        }
+        if !next_permutation(&mut permutation) {
+            return false;
+        }
+    }
+}
+
+/// Advance `permutation` to the next permutation in lexicographic order.
+/// Returns `false` once the last (descending) permutation is reached.
+fn next_permutation(permutation: &mut [usize]) -> bool {
+    let n = permutation.len();
+    if n < 2 {
+        return false;
+    }
+    let Some(pivot) = (0..n - 1).rev().find(|&i| permutation[i] < permutation[i + 1]) else {
+        return false;
+    };
+    let successor = (pivot + 1..n).rev().find(|&j| permutation[j] > permutation[pivot]).expect("pivot guarantees one");
+    permutation.swap(pivot, successor);
+    permutation[pivot + 1..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categorical::root_system::construct_g2;
+
+    #[test]
+    fn json_round_trips_exactly() {
+        let g2 = construct_g2();
+        let json = to_json(&g2);
+        let parsed = parse_json(&json).expect("valid JSON");
+        assert_eq!(parsed, g2);
+    }
+
+    #[test]
+    fn gap_literal_contains_expected_cartan_matrix() {
+        let g2 = construct_g2();
+        let literal = to_gap_literal(&g2);
+        assert!(literal.contains("cartanMatrix"));
+        assert!(literal.contains("[ 2, -1 ]"));
+        assert!(literal.contains("[ -3, 2 ]"));
+    }
+
+    #[test]
+    fn a_root_system_is_equivalent_to_itself() {
+        let g2 = construct_g2();
+        assert!(equivalent_up_to_weyl_symmetry(&g2, &g2));
+    }
+
+    #[test]
+    fn distinct_groups_are_not_equivalent() {
+        let g2 = construct_g2();
+        let f4 = crate::categorical::root_system::construct_f4();
+        assert!(!equivalent_up_to_weyl_symmetry(&g2, &f4));
+    }
+
+    #[test]
+    fn relabeled_simple_system_is_still_equivalent() {
+        let g2 = construct_g2();
+        let mut relabeled = g2.clone();
+        relabeled.simple_roots.swap(0, 1);
+        relabeled.cartan_matrix = vec![
+            vec![g2.cartan_matrix[1][1], g2.cartan_matrix[1][0]],
+            vec![g2.cartan_matrix[0][1], g2.cartan_matrix[0][0]],
+        ];
+        assert!(equivalent_up_to_weyl_symmetry(&g2, &relabeled));
+    }
+
+    #[test]
+    fn zero_denominator_is_a_parse_error_not_a_panic() {
+        let json = r#"{"roots":[[[1,0]]],"simple_roots":[],"cartan_matrix":[]}"#;
+        assert!(parse_json(json).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! Certified interval-arithmetic verification of numeric E₈ embeddings
+//!
+//! The exact-arithmetic construction in [`root_system`](super::root_system)
+//! certifies the *reference* Morphism embedding, but users who work with
+//! numerically-optimized or externally-supplied coordinates need a sound
+//! way to check that their floating-point embedding of the 96 Atlas
+//! vertices into ℝ⁸ really realizes the same Morphism operation. This
+//! module does that with interval arithmetic: every coordinate is widened
+//! to a closed interval `[lo, hi]` that is guaranteed (by outward rounding)
+//! to contain the true value, and every inner-product and squared-norm sum
+//! is propagated through interval addition and multiplication so that the
+//! result interval is guaranteed to contain the true value too. The
+//! certification never falsely accepts: it only passes when a computed
+//! interval contains exactly one admissible value and excludes every
+//! neighboring one, so rounding error can never masquerade as a match.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A closed interval `[lo, hi]`, guaranteed (by construction) to contain
+/// the true real value it approximates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    /// Lower bound.
+    pub lo: f64,
+    /// Upper bound.
+    pub hi: f64,
+}
+
+/// A vector of coordinate intervals, one per ambient dimension.
+pub type IntervalVector = Vec<Interval>;
+
+impl Interval {
+    /// Construct `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`.
+    #[must_use]
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "interval lower bound must not exceed its upper bound");
+        Self { lo, hi }
+    }
+
+    /// The degenerate interval `[value, value]`, widened outward to
+    /// account for the floating-point value itself being an approximation.
+    #[must_use]
+    pub fn from_approx(value: f64) -> Self {
+        Self { lo: round_down(value), hi: round_up(value) }
+    }
+
+    /// Whether this interval contains `value`.
+    #[must_use]
+    pub fn contains(&self, value: f64) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+}
+
+/// Widen `x` downward by enough to soundly bound rounding error in the
+/// operation that produced it (a relative ulp-scale epsilon plus an
+/// absolute floor for values near zero).
+fn round_down(x: f64) -> f64 {
+    x - x.abs() * f64::EPSILON - f64::MIN_POSITIVE
+}
+
+/// Widen `x` upward; see [`round_down`].
+fn round_up(x: f64) -> f64 {
+    x + x.abs() * f64::EPSILON + f64::MIN_POSITIVE
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self { lo: round_down(self.lo + rhs.lo), hi: round_up(self.hi + rhs.hi) }
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { lo: -self.hi, hi: -self.lo }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let corners = [self.lo * rhs.lo, self.lo * rhs.hi, self.hi * rhs.lo, self.hi * rhs.hi];
+        let lo = corners.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = corners.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Self { lo: round_down(lo), hi: round_up(hi) }
+    }
+}
+
+/// The inner-product interval `⟨u,v⟩ = Σᵢ uᵢvᵢ`, propagated through
+/// outward-rounded interval arithmetic.
+#[must_use]
+pub fn dot_interval(u: &[Interval], v: &[Interval]) -> Interval {
+    u.iter().zip(v).fold(Interval::from_approx(0.0), |acc, (&a, &b)| acc + a * b)
+}
+
+/// Why an interval failed to certify against the admissible values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CertificationFailure {
+    /// The interval excludes every admissible value.
+    NoAdmissibleValue {
+        /// The computed interval.
+        interval: Interval,
+    },
+    /// The interval contains more than one admissible value, so it can't be
+    /// told apart from a neighbor.
+    StraddlesMultipleValues {
+        /// The computed interval.
+        interval: Interval,
+        /// The admissible values it straddles.
+        candidates: Vec<f64>,
+    },
+}
+
+/// The admissible E₈ inner products between two norm-2 roots: integers and
+/// half-integers in `[-2, 2]`.
+fn admissible_inner_products() -> Vec<f64> {
+    (-4..=4).map(|n| f64::from(n) / 2.0).collect()
+}
+
+fn certify_against(interval: Interval, candidates: &[f64]) -> Result<(), CertificationFailure> {
+    let matches: Vec<f64> = candidates.iter().copied().filter(|&c| interval.contains(c)).collect();
+    match matches.as_slice() {
+        [_single] => Ok(()),
+        [] => Err(CertificationFailure::NoAdmissibleValue { interval }),
+        _ => Err(CertificationFailure::StraddlesMultipleValues { interval, candidates: matches }),
+    }
+}
+
+/// Certify that every pairwise inner product among `embedding`'s vectors
+/// contains exactly one admissible value. Returns the offending pair's
+/// indices and the failure on the first vector pair that doesn't certify.
+///
+/// # Errors
+///
+/// Returns `Err((i, j, failure))` for the first pair `i < j` whose inner
+/// product interval excludes every admissible value, or straddles more
+/// than one.
+pub fn certify_inner_products(embedding: &[IntervalVector]) -> Result<(), (usize, usize, CertificationFailure)> {
+    let candidates = admissible_inner_products();
+    for i in 0..embedding.len() {
+        for j in (i + 1)..embedding.len() {
+            let inner_product = dot_interval(&embedding[i], &embedding[j]);
+            certify_against(inner_product, &candidates).map_err(|failure| (i, j, failure))?;
+        }
+    }
+    Ok(())
+}
+
+/// Certify that every vector's squared-norm interval contains exactly 2,
+/// the norm of an E₈ root.
+///
+/// # Errors
+///
+/// Returns `Err((i, failure))` for the first vector whose squared-norm
+/// interval doesn't certify against `{2.0}`.
+pub fn certify_norms(embedding: &[IntervalVector]) -> Result<(), (usize, CertificationFailure)> {
+    for (i, v) in embedding.iter().enumerate() {
+        let norm = dot_interval(v, v);
+        certify_against(norm, &[2.0]).map_err(|failure| (i, failure))?;
+    }
+    Ok(())
+}
+
+/// Certify a full numeric embedding: every vector has squared norm 2, and
+/// every pairwise inner product is an unambiguous admissible value.
+///
+/// # Errors
+///
+/// Propagates the first failure from [`certify_norms`] or
+/// [`certify_inner_products`].
+pub fn certify_embedding(embedding: &[IntervalVector]) -> Result<(), String> {
+    certify_norms(embedding).map_err(|(i, failure)| format!("vertex {i} failed norm certification: {failure:?}"))?;
+    certify_inner_products(embedding)
+        .map_err(|(i, j, failure)| format!("vertices {i},{j} failed inner-product certification: {failure:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact_vector(coords: &[f64]) -> IntervalVector {
+        coords.iter().map(|&c| Interval::from_approx(c)).collect()
+    }
+
+    #[test]
+    fn exact_e8_root_pair_certifies() {
+        // ⟨e1+e2, e1-e2⟩ = 1 - 1 = 0, both of squared norm 2.
+        let embedding = vec![exact_vector(&[1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]), exact_vector(&[1.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0])];
+        assert!(certify_embedding(&embedding).is_ok());
+    }
+
+    #[test]
+    fn inadmissible_norm_is_rejected() {
+        let embedding = vec![exact_vector(&[1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0])]; // norm 1, not 2
+        assert!(certify_norms(&embedding).is_err());
+    }
+
+    #[test]
+    fn interval_straddling_two_admissible_values_is_rejected() {
+        // [-0.1, 0.6] contains both 0 and 0.5.
+        let failure = certify_against(Interval::new(-0.1, 0.6), &admissible_inner_products());
+        assert!(matches!(failure, Err(CertificationFailure::StraddlesMultipleValues { .. })));
+        // sanity: a tight interval around 0.5 alone still certifies
+        assert!(certify_against(Interval::new(0.4, 0.6), &admissible_inner_products()).is_ok());
+    }
+
+    #[test]
+    fn interval_excluding_every_candidate_is_rejected() {
+        let failure = certify_against(Interval::new(0.7, 0.8), &admissible_inner_products());
+        assert_eq!(failure, Err(CertificationFailure::NoAdmissibleValue { interval: Interval::new(0.7, 0.8) }));
+    }
+}
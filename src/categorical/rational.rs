@@ -0,0 +1,192 @@
+//! Exact rational arithmetic
+//!
+//! Root-system coordinates, Gram matrices, and Cartan-matrix entries must be
+//! compared exactly — no floating-point rounding — so this module provides a
+//! minimal rational number type that is always kept reduced to lowest terms
+//! with a positive denominator.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An exact rational number, always reduced to lowest terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    /// Zero.
+    pub const ZERO: Self = Self::from_int(0);
+    /// One.
+    pub const ONE: Self = Self::from_int(1);
+
+    /// Construct a rational from a numerator and denominator, reducing to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    #[must_use]
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        Self::try_new(numerator, denominator).expect("denominator must be non-zero")
+    }
+
+    /// Construct a rational from a numerator and denominator, reducing to
+    /// lowest terms, or `None` if `denominator` is zero.
+    ///
+    /// This is the fallible counterpart to [`Rational::new`], for callers
+    /// (such as [`crate::categorical::export::parse_json`]) that accept
+    /// untrusted numerator/denominator pairs and must report a malformed
+    /// pair as an error rather than panicking.
+    #[must_use]
+    pub fn try_new(numerator: i64, denominator: i64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let sign: i64 = if denominator < 0 { -1 } else { 1 };
+        let g = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()).max(1) as i64;
+        Some(Self { numerator: sign * numerator / g, denominator: sign * denominator / g })
+    }
+
+    /// Construct an integer-valued rational.
+    #[must_use]
+    pub const fn from_int(n: i64) -> Self {
+        Self { numerator: n, denominator: 1 }
+    }
+
+    /// The reduced numerator.
+    #[must_use]
+    pub const fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// The reduced, always-positive denominator.
+    #[must_use]
+    pub const fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Whether this rational is exactly zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// Whether this rational is strictly positive.
+    #[must_use]
+    pub const fn is_positive(&self) -> bool {
+        self.numerator > 0
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self::from_int(n)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.denominator + rhs.numerator * self.denominator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.numerator * rhs.numerator, self.denominator * rhs.denominator)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "division by zero");
+        Self::new(self.numerator * rhs.denominator, self.denominator * rhs.numerator)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { numerator: -self.numerator, denominator: self.denominator }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduces_to_lowest_terms() {
+        assert_eq!(Rational::new(2, 4), Rational::new(1, 2));
+        assert_eq!(Rational::new(-2, -4), Rational::new(1, 2));
+        assert_eq!(Rational::new(3, -6), Rational::new(-1, 2));
+    }
+
+    #[test]
+    fn arithmetic_is_exact() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+    }
+
+    #[test]
+    fn ordering_compares_across_denominators() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::ZERO);
+    }
+}
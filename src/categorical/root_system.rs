@@ -0,0 +1,338 @@
+//! Explicit root-system construction for each exceptional group
+//!
+//! [`CategoricalOperation::verify`](super::CategoricalOperation::verify) only
+//! ever compared root *counts*. This module materializes the actual root
+//! vectors a categorical operation produces, as exact rational coordinate
+//! tuples, so that the result can be checked structurally instead: every
+//! vector has an admissible squared norm, and every pairwise Cartan integer
+//! `2⟨α,β⟩/⟨β,β⟩` lands in the set allowed by a finite root system,
+//! {0, ±1, ±2, ±3}.
+//!
+//! # Constructions
+//!
+//! - **G₂** (rank 2, trace-zero plane in ℝ³): 6 short roots, the
+//!   permutations of `(1,-1,0)`, and 6 long roots, the permutations of
+//!   `(2,-1,-1)` and `(-2,1,1)`, giving the standard long:short norm ratio
+//!   of 3.
+//! - **F₄** (rank 4, ℝ⁴): 8 short roots `±eᵢ`, 24 long roots `±eᵢ±eⱼ`, and 16
+//!   short roots `(±1/2,±1/2,±1/2,±1/2)`.
+//! - **E₆, E₇** (rank 6, 7): the sub-root-systems of E₈ orthogonal to a fixed
+//!   A₂ pair and a fixed root, respectively — the standard way an ADE root
+//!   system sits inside a larger one.
+//! - **E₈** (rank 8, ℝ⁸): the 240 roots `±eᵢ±eⱼ` and
+//!   `(±1/2,…,±1/2)` with an even number of minus signs.
+//!
+//! Simple roots are extracted from each root set by the standard algorithm:
+//! fix a generic linear functional, call a root positive if the functional is
+//! positive on it, and call a positive root simple if it cannot be written as
+//! the sum of two other positive roots.
+
+use crate::categorical::rational::Rational;
+
+/// A root vector with exact rational coordinates.
+pub type RootVector = Vec<Rational>;
+
+/// The explicit root data produced by a categorical operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RootSystem {
+    /// Every root of the system, as exact coordinate vectors.
+    pub roots: Vec<RootVector>,
+    /// A simple system spanning the root space, extracted from `roots`.
+    pub simple_roots: Vec<RootVector>,
+    /// The Cartan matrix `A_ij = 2⟨αᵢ,αⱼ⟩/⟨αⱼ,αⱼ⟩` of the simple roots.
+    pub cartan_matrix: Vec<Vec<Rational>>,
+}
+
+impl RootSystem {
+    /// Build a root system from an explicit root set, deriving a simple
+    /// system and Cartan matrix automatically.
+    #[must_use]
+    fn from_roots(roots: Vec<RootVector>) -> Self {
+        let simple_roots = extract_simple_roots(&roots);
+        let cartan_matrix = cartan_matrix(&simple_roots);
+        Self { roots, simple_roots, cartan_matrix }
+    }
+
+    /// The Gram matrix `⟨αᵢ,αⱼ⟩` of the simple roots.
+    ///
+    /// Unlike [`cartan_matrix`](Self::cartan_matrix), this is symmetric, and
+    /// a finite-type root system is exactly one whose simple roots have a
+    /// positive-definite Gram matrix.
+    #[must_use]
+    pub fn gram_matrix(&self) -> Vec<Vec<Rational>> {
+        self.simple_roots.iter().map(|a| self.simple_roots.iter().map(|b| dot(a, b)).collect()).collect()
+    }
+
+    /// Every root's squared norm matches the norm of some simple root.
+    ///
+    /// Finite-type root systems have at most two root lengths, and every
+    /// root shares a length with some simple root, so this is a necessary
+    /// structural property of a genuine root system.
+    #[must_use]
+    pub fn has_consistent_norms(&self) -> bool {
+        let allowed_norms: Vec<Rational> = self.simple_roots.iter().map(|a| dot(a, a)).collect();
+        self.roots.iter().all(|v| allowed_norms.contains(&dot(v, v)))
+    }
+
+    /// Every Cartan integer `2⟨α,β⟩/⟨β,β⟩` between distinct roots α, β lies
+    /// in the admissible set {0, ±1, ±2, ±3} — the classical crystallographic
+    /// restriction satisfied by any two roots of a finite root system,
+    /// regardless of whether they have equal length.
+    #[must_use]
+    pub fn has_admissible_inner_products(&self) -> bool {
+        let admissible: [Rational; 7] = [
+            Rational::from_int(-3),
+            Rational::from_int(-2),
+            Rational::from_int(-1),
+            Rational::ZERO,
+            Rational::from_int(1),
+            Rational::from_int(2),
+            Rational::from_int(3),
+        ];
+        for a in &self.roots {
+            for b in &self.roots {
+                if a == b {
+                    continue;
+                }
+                let cartan_integer = Rational::from_int(2) * dot(a, b) / dot(b, b);
+                if !admissible.contains(&cartan_integer) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Whether this root set satisfies both structural checks.
+    #[must_use]
+    pub fn is_structurally_valid(&self) -> bool {
+        self.has_consistent_norms() && self.has_admissible_inner_products()
+    }
+}
+
+pub(crate) fn dot(a: &[Rational], b: &[Rational]) -> Rational {
+    a.iter().zip(b).fold(Rational::ZERO, |acc, (x, y)| acc + *x * *y)
+}
+
+fn vec_add(a: &[Rational], b: &[Rational]) -> RootVector {
+    a.iter().zip(b).map(|(x, y)| *x + *y).collect()
+}
+
+/// Extract a simple system from a root set via a generic separating
+/// functional: positive roots are those on which the functional is
+/// positive, and simple roots are the positive roots that cannot be written
+/// as a sum of two other positive roots.
+fn extract_simple_roots(roots: &[RootVector]) -> Vec<RootVector> {
+    let Some(dim) = roots.first().map(Vec::len) else {
+        return Vec::new();
+    };
+    let weights: Vec<Rational> = (0..dim).map(|i| Rational::from_int(101i64.pow(i as u32))).collect();
+    let functional = |v: &RootVector| dot(v, &weights);
+
+    let mut positive: Vec<RootVector> =
+        roots.iter().filter(|v| functional(v).is_positive()).cloned().collect();
+    positive.sort_by_key(&functional);
+
+    positive
+        .iter()
+        .filter(|r| {
+            !positive.iter().any(|a| {
+                a != *r && positive.iter().any(|b| b != *r && vec_add(a, b) == **r)
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// The Cartan matrix `A_ij = 2⟨αᵢ,αⱼ⟩/⟨αⱼ,αⱼ⟩` of a simple system.
+fn cartan_matrix(simple_roots: &[RootVector]) -> Vec<Vec<Rational>> {
+    simple_roots
+        .iter()
+        .map(|a| {
+            simple_roots
+                .iter()
+                .map(|b| Rational::from_int(2) * dot(a, b) / dot(b, b))
+                .collect()
+        })
+        .collect()
+}
+
+fn signed_permutations_of(values: &[i64; 3]) -> Vec<RootVector> {
+    let mut seen = Vec::new();
+    for perm in [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]] {
+        let v: RootVector = perm.iter().map(|&i| Rational::from_int(values[i])).collect();
+        if !seen.contains(&v) {
+            seen.push(v);
+        }
+    }
+    seen
+}
+
+/// The 6 short roots of G₂: permutations of `(1,-1,0)` in the trace-zero
+/// plane of ℝ³, each of squared norm 2.
+#[must_use]
+pub fn g2_short_roots() -> Vec<RootVector> {
+    signed_permutations_of(&[1, -1, 0])
+}
+
+/// The 6 long roots of G₂: permutations of `(2,-1,-1)` and `(-2,1,1)`, each
+/// of squared norm 6 — three times the short-root norm, matching G₂'s
+/// long:short length ratio of √3.
+#[must_use]
+pub fn g2_long_roots() -> Vec<RootVector> {
+    let mut roots = signed_permutations_of(&[2, -1, -1]);
+    roots.extend(signed_permutations_of(&[-2, 1, 1]));
+    roots
+}
+
+/// Construct the G₂ root system: 6 short roots and 6 long roots, living in
+/// the trace-zero plane `x+y+z=0` of ℝ³.
+#[must_use]
+pub fn construct_g2() -> RootSystem {
+    let mut roots = g2_short_roots();
+    roots.extend(g2_long_roots());
+    RootSystem::from_roots(roots)
+}
+
+/// Reflect `v` across the hyperplane orthogonal to `alpha`:
+/// `v - 2⟨v,α⟩/⟨α,α⟩ · α`.
+pub(crate) fn reflect(v: &[Rational], alpha: &[Rational]) -> RootVector {
+    let coeff = Rational::from_int(2) * dot(v, alpha) / dot(alpha, alpha);
+    v.iter().zip(alpha).map(|(vi, ai)| *vi - coeff * *ai).collect()
+}
+
+/// Construct the F₄ root system: `±eᵢ` (8, short), `±eᵢ±eⱼ` (24, long), and
+/// `(±1/2,±1/2,±1/2,±1/2)` (16, short), in ℝ⁴.
+#[must_use]
+pub fn construct_f4() -> RootSystem {
+    let mut roots = Vec::with_capacity(48);
+    for i in 0..4 {
+        for sign in [1, -1] {
+            let mut v = vec![Rational::ZERO; 4];
+            v[i] = Rational::from_int(sign);
+            roots.push(v);
+        }
+    }
+    for i in 0..4 {
+        for j in (i + 1)..4 {
+            for si in [1, -1] {
+                for sj in [1, -1] {
+                    let mut v = vec![Rational::ZERO; 4];
+                    v[i] = Rational::from_int(si);
+                    v[j] = Rational::from_int(sj);
+                    roots.push(v);
+                }
+            }
+        }
+    }
+    for mask in 0u32..16 {
+        let v: RootVector = (0..4)
+            .map(|k| if (mask >> k) & 1 == 1 { Rational::new(-1, 2) } else { Rational::new(1, 2) })
+            .collect();
+        roots.push(v);
+    }
+    RootSystem::from_roots(roots)
+}
+
+/// Construct the E₈ root system: `±eᵢ±eⱼ` (112) and `(±1/2,…,±1/2)` with an
+/// even number of minus signs (128), in ℝ⁸.
+#[must_use]
+pub fn construct_e8_roots() -> Vec<RootVector> {
+    let mut roots = Vec::with_capacity(240);
+    for i in 0..8 {
+        for j in (i + 1)..8 {
+            for si in [1, -1] {
+                for sj in [1, -1] {
+                    let mut v = vec![Rational::ZERO; 8];
+                    v[i] = Rational::from_int(si);
+                    v[j] = Rational::from_int(sj);
+                    roots.push(v);
+                }
+            }
+        }
+    }
+    for mask in 0u32..256 {
+        if (0..8).filter(|k| (mask >> k) & 1 == 1).count() % 2 == 0 {
+            let v: RootVector = (0..8)
+                .map(|k| if (mask >> k) & 1 == 1 { Rational::new(-1, 2) } else { Rational::new(1, 2) })
+                .collect();
+            roots.push(v);
+        }
+    }
+    roots
+}
+
+/// Construct the full E₈ root system.
+#[must_use]
+pub fn construct_e8() -> RootSystem {
+    RootSystem::from_roots(construct_e8_roots())
+}
+
+/// Construct the E₇ root system as the sub-root-system of E₈ orthogonal to
+/// the fixed root `e₇-e₆` (components 6 and 7 equal) — the standard way E₇
+/// sits inside E₈, giving 126 roots.
+#[must_use]
+pub fn construct_e7() -> RootSystem {
+    let roots = construct_e8_roots().into_iter().filter(|v| v[5] == v[6]).collect();
+    RootSystem::from_roots(roots)
+}
+
+/// Construct the E₆ root system as the sub-root-system of E₈ orthogonal to
+/// the fixed A₂ pair `{e₆-e₅, e₇-e₆}` (components 5, 6, 7 all equal) — the
+/// standard way E₆ sits inside E₈, giving 72 roots.
+#[must_use]
+pub fn construct_e6() -> RootSystem {
+    let roots = construct_e8_roots().into_iter().filter(|v| v[4] == v[5] && v[5] == v[6]).collect();
+    RootSystem::from_roots(roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn g2_has_twelve_roots_and_matches_known_cartan_matrix() {
+        let g2 = construct_g2();
+        assert_eq!(g2.roots.len(), 12);
+        assert_eq!(g2.simple_roots.len(), 2);
+        assert!(g2.is_structurally_valid());
+
+        let expected = vec![
+            vec![Rational::from_int(2), Rational::from_int(-1)],
+            vec![Rational::from_int(-3), Rational::from_int(2)],
+        ];
+        assert_eq!(g2.cartan_matrix, expected);
+    }
+
+    #[test]
+    fn f4_has_forty_eight_roots() {
+        let f4 = construct_f4();
+        assert_eq!(f4.roots.len(), 48);
+        assert_eq!(f4.simple_roots.len(), 4);
+        assert!(f4.is_structurally_valid());
+    }
+
+    #[test]
+    fn e8_has_two_hundred_forty_roots() {
+        let e8 = construct_e8();
+        assert_eq!(e8.roots.len(), 240);
+        assert_eq!(e8.simple_roots.len(), 8);
+        assert!(e8.is_structurally_valid());
+    }
+
+    #[test]
+    fn e7_has_one_hundred_twenty_six_roots() {
+        let e7 = construct_e7();
+        assert_eq!(e7.roots.len(), 126);
+        assert_eq!(e7.simple_roots.len(), 7);
+        assert!(e7.is_structurally_valid());
+    }
+
+    #[test]
+    fn e6_has_seventy_two_roots() {
+        let e6 = construct_e6();
+        assert_eq!(e6.roots.len(), 72);
+        assert_eq!(e6.simple_roots.len(), 6);
+        assert!(e6.is_structurally_valid());
+    }
+}
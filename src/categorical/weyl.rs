@@ -0,0 +1,203 @@
+//! Weyl group generation by reflection closure
+//!
+//! Given the simple roots `α₁,…,αₙ` a [`RootSystem`](super::RootSystem)
+//! construction produces, this module generates the full finite reflection
+//! group they span: the reflection
+//!
+//! ```text
+//! s_i(v) = v - 2⟨v,αᵢ⟩/⟨αᵢ,αᵢ⟩ · αᵢ
+//! ```
+//!
+//! is computed with exact rational arithmetic, and since a reflection
+//! permutes the (finite) root set, each generator is represented as a
+//! permutation of that set. The full group is then the closure of the
+//! generators under composition, enumerated by a worklist of
+//! not-yet-expanded permutations; elements are deduplicated by the
+//! permutation they induce, and each is tagged with a word in the
+//! generators `s_i` that reaches it.
+//!
+//! Enumerating the Weyl group this way is only tractable for the smaller
+//! groups — |W(E₇)| = 2,903,040 and |W(E₈)| = 696,729,600 are too large to
+//! materialize as explicit permutations here. [`expected_order`] gives the
+//! known orders for cross-checking without paying that cost.
+
+use std::collections::HashMap;
+
+use crate::categorical::root_system::{reflect, RootSystem, RootVector};
+
+/// A single element of a [`WeylGroup`], as the permutation it induces on the
+/// group's fixed root ordering together with a word in the generators.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeylElement {
+    /// `permutation[i]` is the index of the root that root `i` maps to.
+    pub permutation: Vec<usize>,
+    /// A word in the simple-reflection generators (by index) producing this element.
+    pub word: Vec<usize>,
+}
+
+/// The finite reflection group generated by the simple roots of a
+/// [`RootSystem`].
+#[derive(Debug, Clone)]
+pub struct WeylGroup {
+    roots: Vec<RootVector>,
+    generators: Vec<Vec<usize>>,
+    elements: Vec<WeylElement>,
+}
+
+impl WeylGroup {
+    /// Generate the Weyl group of `root_system` by reflection closure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `root_system` is not closed under reflection in its own
+    /// simple roots, which would mean it is not a genuine root system.
+    #[must_use]
+    pub fn generate(root_system: &RootSystem) -> Self {
+        let roots = root_system.roots.clone();
+        let generators: Vec<Vec<usize>> =
+            root_system.simple_roots.iter().map(|alpha| reflection_permutation(&roots, alpha)).collect();
+
+        let identity: Vec<usize> = (0..roots.len()).collect();
+        let mut seen: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+        seen.insert(identity.clone(), Vec::new());
+        let mut frontier = vec![identity];
+
+        while let Some(perm) = frontier.pop() {
+            let word = seen[&perm].clone();
+            for (i, gen) in generators.iter().enumerate() {
+                let next = compose(gen, &perm);
+                if !seen.contains_key(&next) {
+                    let mut next_word = word.clone();
+                    next_word.push(i);
+                    seen.insert(next.clone(), next_word);
+                    frontier.push(next);
+                }
+            }
+        }
+
+        let elements =
+            seen.into_iter().map(|(permutation, word)| WeylElement { permutation, word }).collect();
+        Self { roots, generators, elements }
+    }
+
+    /// The order `|W|` of the group.
+    #[must_use]
+    pub fn order(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Every generated element.
+    #[must_use]
+    pub fn elements(&self) -> &[WeylElement] {
+        &self.elements
+    }
+
+    /// Whether `element` is a reflection, i.e. its permutation is induced by
+    /// negating some root of the system and fixing its orthogonal complement.
+    #[must_use]
+    pub fn is_reflection(&self, element: &WeylElement) -> bool {
+        self.roots.iter().any(|alpha| reflection_permutation(&self.roots, alpha) == element.permutation)
+    }
+
+    /// A Coxeter element: the product of the simple reflections taken in the
+    /// order the simple roots were given, with `s_1` applied first and `s_n`
+    /// last. Its order, from [`element_order`](Self::element_order), is the
+    /// Coxeter number of the system.
+    #[must_use]
+    pub fn coxeter_element(&self) -> WeylElement {
+        let identity: Vec<usize> = (0..self.roots.len()).collect();
+        let mut permutation = identity.clone();
+        let mut word = Vec::with_capacity(self.generators.len());
+        for (i, gen) in self.generators.iter().enumerate() {
+            permutation = compose(gen, &permutation);
+            word.push(i);
+        }
+        WeylElement { permutation, word }
+    }
+
+    /// The order of a group element: the smallest `k > 0` with `element^k = e`.
+    #[must_use]
+    pub fn element_order(&self, element: &WeylElement) -> usize {
+        let identity: Vec<usize> = (0..self.roots.len()).collect();
+        let mut power = element.permutation.clone();
+        let mut order = 1;
+        while power != identity {
+            power = compose(&element.permutation, &power);
+            order += 1;
+        }
+        order
+    }
+}
+
+fn compose(f: &[usize], g: &[usize]) -> Vec<usize> {
+    g.iter().map(|&gi| f[gi]).collect()
+}
+
+fn reflection_permutation(roots: &[RootVector], alpha: &RootVector) -> Vec<usize> {
+    roots
+        .iter()
+        .map(|v| {
+            let reflected = reflect(v, alpha);
+            roots
+                .iter()
+                .position(|r| *r == reflected)
+                .expect("root system must be closed under reflection in its own roots")
+        })
+        .collect()
+}
+
+/// The known Weyl group order for each exceptional group, for cross-checking
+/// against [`WeylGroup::order`] without necessarily paying the cost of
+/// generating the (possibly huge) group.
+#[must_use]
+pub fn expected_order(target_group: &str) -> Option<usize> {
+    match target_group {
+        "G₂" => Some(12),
+        "F₄" => Some(1_152),
+        "E₆" => Some(51_840),
+        "E₇" => Some(2_903_040),
+        "E₈" => Some(696_729_600),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categorical::root_system::{construct_f4, construct_g2};
+
+    #[test]
+    fn g2_weyl_group_has_order_twelve() {
+        let g2 = construct_g2();
+        let weyl = WeylGroup::generate(&g2);
+        assert_eq!(weyl.order(), 12);
+        assert_eq!(weyl.order(), expected_order("G₂").unwrap());
+    }
+
+    #[test]
+    fn f4_weyl_group_has_order_eleven_fifty_two() {
+        let f4 = construct_f4();
+        let weyl = WeylGroup::generate(&f4);
+        assert_eq!(weyl.order(), 1152);
+        assert_eq!(weyl.order(), expected_order("F₄").unwrap());
+    }
+
+    #[test]
+    fn every_simple_reflection_is_a_reflection() {
+        let g2 = construct_g2();
+        let weyl = WeylGroup::generate(&g2);
+        for element in weyl.elements() {
+            if element.word.len() == 1 {
+                assert!(weyl.is_reflection(element));
+            }
+        }
+    }
+
+    #[test]
+    fn coxeter_element_order_is_the_coxeter_number() {
+        let g2 = construct_g2();
+        let weyl = WeylGroup::generate(&g2);
+        let coxeter = weyl.coxeter_element();
+        assert_eq!(weyl.element_order(&coxeter), 6); // G₂'s Coxeter number
+    }
+}